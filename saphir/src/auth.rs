@@ -0,0 +1,51 @@
+//! Runtime support for `#[auth(...)]`-guarded endpoints: bearer token
+//! extraction and JWT decoding. The macro expansion calls into this module;
+//! it doesn't reimplement JWT handling itself.
+
+use std::sync::OnceLock;
+
+use jsonwebtoken::{decode, DecodingKey, Validation};
+use serde::de::DeserializeOwned;
+
+use crate::responder::HttpContext;
+
+static SECRET: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// Configures the HMAC secret `#[auth(...)]`-guarded endpoints validate
+/// tokens against. Falls back to a fixed development secret if never called.
+pub fn set_secret(secret: impl Into<Vec<u8>>) {
+    let _ = SECRET.set(secret.into());
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AuthError {
+    MissingToken,
+    InvalidToken,
+}
+
+/// Implemented by a claims type so `#[auth(scopes = [...])]` can check them.
+pub trait Scoped {
+    fn scopes(&self) -> &[String];
+}
+
+pub fn extract_bearer(ctx: &HttpContext) -> Option<String> {
+    ctx.headers.get("Authorization")?.strip_prefix("Bearer ").map(|s| s.to_string())
+}
+
+pub fn decode_bearer<T: DeserializeOwned>(ctx: &HttpContext) -> Result<T, AuthError> {
+    let token = extract_bearer(ctx).ok_or(AuthError::MissingToken)?;
+    let secret = SECRET.get().map(|s| s.as_slice()).unwrap_or(b"saphir-dev-secret");
+    let mut validation = Validation::default();
+    // Claims types only need to carry whatever scopes/identity fields the
+    // endpoint cares about; don't force every one of them to also declare an
+    // `exp` claim. `jsonwebtoken` only enforces expiry when an `exp` claim is
+    // actually present, so clearing this doesn't disable expiry checking.
+    validation.required_spec_claims.clear();
+    let data =
+        decode::<T>(&token, &DecodingKey::from_secret(secret), &validation).map_err(|_| AuthError::InvalidToken)?;
+    Ok(data.claims)
+}
+
+pub fn has_scopes<C: Scoped>(claims: &C, required: &[&str]) -> bool {
+    required.iter().all(|scope| claims.scopes().iter().any(|s| s == scope))
+}
@@ -0,0 +1,25 @@
+/// The HTTP verb an endpoint is registered under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+    Any,
+}
+
+/// One route, as derived by `#[controller]` or `#[resource]` from either an
+/// explicit `#[get(...)]`-style marker or a conventionally-named method.
+#[derive(Debug, Clone)]
+pub struct Endpoint {
+    pub method: Method,
+    pub path: String,
+}
+
+/// Implemented by every `#[controller]`/`#[resource]`-tagged type, listing
+/// the routes it registers.
+pub trait Controller {
+    fn base_path() -> &'static str;
+    fn endpoints() -> Vec<Endpoint>;
+}
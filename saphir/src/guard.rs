@@ -0,0 +1,18 @@
+use crate::responder::{Builder, HttpContext, Responder};
+
+/// The return type `#[guard(...)]` and `#[auth(...)]` rewrite an endpoint to
+/// produce: either the request was rejected with a status code, or the
+/// wrapped endpoint's own responder runs normally.
+pub enum GuardOutcome<T> {
+    Rejected(u16),
+    Allowed(T),
+}
+
+impl<T: Responder> Responder for GuardOutcome<T> {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder {
+        match self {
+            GuardOutcome::Rejected(status) => builder.status(status),
+            GuardOutcome::Allowed(inner) => inner.respond_with_builder(builder, ctx),
+        }
+    }
+}
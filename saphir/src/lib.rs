@@ -0,0 +1,20 @@
+//! Saphir is a progressive http framework for rust, built on top of hyper.
+
+pub mod auth;
+pub mod controller;
+pub mod guard;
+mod macros;
+pub mod openapi;
+pub mod responder;
+pub mod wrap;
+
+pub use macros::*;
+pub use responder::{Builder, HttpContext, Json, Responder};
+
+pub mod prelude {
+    pub use crate::auth::Scoped;
+    pub use crate::controller::{Controller, Endpoint, Method};
+    pub use crate::guard::GuardOutcome;
+    pub use crate::responder::{Builder, HttpContext, Json, Responder};
+    pub use crate::{auth, controller, guard, header, middleware, openapi, resource, status};
+}
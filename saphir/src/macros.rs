@@ -5,7 +5,9 @@
 //! This macro is an attribute macro that need to be place on the `impl block`
 //! of a Saphir controller. It has 3 optionnal parameters:
 //! - `prefix="<pre>"` : This will prefix any controller route by the specified
-//!   route prefix
+//!   route prefix. The prefix may contain a dynamic segment, e.g.
+//!   `prefix="/tenants/<tenant_id>"`, which is then shared by every endpoint
+//!   generated from this `impl` block.
 //! - `version=<u16>`  : This will insert the `/v#` path segment between the
 //!   prefix and the base controller route
 //! - `name="<name>"`  : This will route the controller at /<name>.
@@ -13,6 +15,43 @@
 //! If none of these are used, the controller will be routed at its own name, in
 //! lowercase, with the controller keyword trimmed.
 //!
+//! When the `impl` block's `prefix` carries a dynamic segment, pair it with a
+//! `#[openapi(param(name = "<name>", type = "<type>"))]` attribute on the
+//! `impl` block (listed *above* `#[controller]`) to document it once, instead
+//! of repeating `params(path(...))` on each handler. It's exposed via a
+//! hidden `__openapi_controller_params()` accessor on the controller type, and
+//! `#[controller]` also merges it into every generated endpoint's own
+//! OpenAPI metadata automatically. The parameter is required on every
+//! handler's signature too - `#[controller]` rejects, at compile time, any
+//! endpoint that doesn't declare a parameter of that name.
+//! E.g. `#[openapi(param(name = "tenant_id", type = "u64"))]` requires every
+//! endpoint on that controller to take a `tenant_id: u64` parameter.
+//!
+//! # The `#[resource]` Macro
+//!
+//! This macro is an alternative to `#[controller]` for the common case of a
+//! CRUD-shaped controller. Instead of tagging every handler with a
+//! `#[<method>("/<path>")]` attribute, it recognizes a fixed set of
+//! conventionally-named async methods on the `impl` block and derives their
+//! method and path from the function name alone:
+//! - `read_all(&self)` => `GET /<path>`
+//! - `read(&self, id: u32)` => `GET /<path>/<id>`
+//! - `search(&self, query: Query)` => `GET /<path>/search`
+//! - `create(&self, body: Json<T>)` => `POST /<path>`
+//! - `update_all(&self)` => `PUT /<path>`
+//! - `update(&self, id: u32)` => `PUT /<path>/<id>`
+//! - `delete_all(&self)` => `DELETE /<path>`
+//! - `delete(&self, id: u32)` => `DELETE /<path>/<id>`
+//!
+//! It takes a single required parameter:
+//! - `path="/<path>"` : The base route the resource is mounted at.
+//!
+//! Any recognized method that isn't present on the `impl` block is simply
+//! skipped. Under the hood `#[resource]` reuses the same route-registration
+//! codegen as `#[controller]` and emits the same OpenAPI metadata for each
+//! generated endpoint, so `#[openapi(...)]` attributes on the methods still
+//! apply normally.
+//!
 //! # Function Attributes
 //! We also parse several function attributes that can be placed above a
 //! controller function (endpoint)
@@ -63,7 +102,7 @@
 //! 404. So, the following handler :
 //! ```rust
 //! # #[macro_use] extern crate saphir_macro;
-//! # use crate::saphir::prelude::*;
+//! # use saphir::prelude::*;
 //! #
 //! # fn main() {}
 //! #
@@ -86,7 +125,7 @@
 //! will generate by default the same documentation as if it was written as such :
 //! ```rust
 //! # #[macro_use] extern crate saphir_macro;
-//! # use crate::saphir::prelude::*;
+//! # use saphir::prelude::*;
 //! #
 //! # fn main() {}
 //! #
@@ -114,7 +153,7 @@
 //! as a json document, then you can use `return_override` like this :
 //! ```rust
 //! # #[macro_use] extern crate saphir_macro;
-//! # use crate::saphir::prelude::*;
+//! # use saphir::prelude::*;
 //! #
 //! # fn main() {}
 //! #
@@ -136,6 +175,40 @@
 //! # }
 //! ```
 //!
+//! ### `security("<scheme_name>")`
+//! Declares that an endpoint requires the named security scheme, adding a
+//! `security` block to its generated OpenAPI operation. `#[auth(...)]`
+//! endpoints emit this automatically for the `bearerJWT` scheme when
+//! `#[auth(...)]` is listed above their `#[openapi(...)]` attributes; you only
+//! need it directly when guarding an endpoint by hand.
+//! E.g. `#[openapi(security("bearerJWT"))]`
+//!
+//! ### `security_scheme(name = "<name>", type = "<type>"[, scheme = "<scheme>"][, bearer_format = "<format>"])`
+//! Declares a security scheme, making it available for `security(...)` to
+//! reference by name. This is placed on the `impl` block of a controller
+//! rather than on a single endpoint, and contributes a `securitySchemes`
+//! entry to the spec's `components`. On an endpoint it is accepted but
+//! records nothing.
+//! E.g. `#[openapi(security_scheme(name = "bearerJWT", type = "http", scheme = "bearer", bearer_format = "JWT"))]`
+//!
+//! ### `body(type = "<type_path>"[, mime = <mime>])`
+//! Describes the endpoint's request body, populating the generated spec's
+//! `requestBody`. `type` accepts the same values as `return`'s, including
+//! mime inference for built-in responders, so `body(type = "Json<CreateUser>")`
+//! and `body(type = "CreateUser", mime = "json")` are equivalent.
+//!
+//! ### `params(query(name = "<name>", type = "<type>"[, required = <bool>]), path(name = "<name>", type = "<type>"))`
+//! Describes the endpoint's query and path parameters, populating the
+//! generated spec's `parameters` section. `query(...)` parameters default to
+//! `required = true`; `path(...)` parameters are always required. When the
+//! endpoint is registered through `#[controller]`/`#[resource]`, its full
+//! route is recorded on the generated `OpenApiOperation` automatically, and
+//! declared `path(...)` names are cross-checked against the route's
+//! `<name>`-style segments right then: a mismatch prints a warning (via
+//! `saphir::openapi::mismatched_path_params`) as soon as the endpoint's
+//! `__openapi_meta_*` accessor is called.
+//! E.g. `#[openapi(params(query(name = "page", type = "u32", required = false), path(name = "user_id", type = "u64")))]`
+//!
 //! ## The `#[cookies] Attribute`
 //! This will ensure cookies are parsed in the request before the endpoint
 //! function is called, cookies can than be accessed with
@@ -149,6 +222,39 @@
 //!   the data that will be passed to the guard function. this function takes a
 //!   reference of the controller type it is used in.
 //!
+//! ## The `#[auth(...)] Attribute`
+//! This is a specialized `#[guard]` for JWT bearer authentication. It has one
+//! required and one optional parameter:
+//! - `claims="MyClaims"` : *REQUIRED* The type to deserialize the token's
+//!   claims into. This type is injected as a parameter of the endpoint.
+//! - `scopes=["admin"]`  : _Optional_ A list of scopes that must all be
+//!   present in the decoded claims for the request to be let through.
+//!
+//! The generated guard extracts the `Authorization: Bearer <token>` header,
+//! validates its signature and expiry against the configured key, and
+//! deserializes its claims into the provided type. A missing or invalid
+//! token rejects the request with `401 Unauthorized`; a valid token missing
+//! one of the required scopes rejects with `403 Forbidden`. The endpoint is
+//! also documented as requiring the `bearerJWT` security scheme, through a
+//! hidden `__openapi_auth_security_<fn>()` accessor and, when `#[auth(...)]`
+//! is listed above the endpoint's `#[openapi(...)]` attributes (so it expands
+//! first), the same way as adding `#[openapi(security("bearerJWT"))]` by hand.
+//!
+//! ## The `#[status(<code>)] Attribute`
+//! This wraps the endpoint's returned `Responder`, overriding its status code
+//! with `<code>` once the inner responder has run, without having to build a
+//! `Builder` by hand. When it's listed above an `#[openapi(...)]` attribute
+//! on the same endpoint (so it expands first), it also feeds `<code>` into
+//! that endpoint's OpenAPI metadata as its default documented return code -
+//! e.g. `#[status(201)]` alone makes the spec report 201 instead of 200 -
+//! without overriding an explicit `#[openapi(return(code = ...))]` if one is
+//! also present.
+//!
+//! ## The `#[header("<name>", "<value>")] Attribute`
+//! This wraps the endpoint's returned `Responder` the same way `#[status]`
+//! does, setting the header `<name>` to `<value>` on the final response after
+//! the inner responder has run. It can be repeated to set multiple headers.
+//!
 //! # Type Attributes (Struct & Enum)
 //! These attributes can be added on top of a `struct` or `enum` definition.
 //!
@@ -156,4 +262,4 @@
 //! This attribute specify the OpenAPI mimetype for this type.
 
 pub use futures::future::{BoxFuture, FutureExt};
-pub use saphir_macro::{controller, guard, middleware, openapi};
+pub use saphir_macro::{auth, controller, guard, header, middleware, openapi, resource, status};
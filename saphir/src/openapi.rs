@@ -0,0 +1,65 @@
+//! Metadata types populated by the `#[openapi(...)]` attribute. These mirror
+//! the shape of an OpenAPI operation closely enough for saphir's CLI to turn
+//! them into a real spec, without this crate depending on the CLI itself.
+
+#[derive(Debug, Clone, Default)]
+pub struct ReturnSpec {
+    pub codes: Vec<u16>,
+    pub ty: String,
+    pub mime: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BodySpec {
+    pub ty: String,
+    pub mime: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamLocation {
+    Query,
+    Path,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParamSpec {
+    pub name: String,
+    pub ty: String,
+    pub location: ParamLocation,
+    pub required: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SecuritySchemeSpec {
+    pub name: String,
+    pub scheme_type: String,
+    pub scheme: Option<String>,
+    pub bearer_format: Option<String>,
+}
+
+/// The metadata collected for a single endpoint across every
+/// `#[openapi(...)]` attribute attached to it.
+#[derive(Debug, Clone, Default)]
+pub struct OpenApiOperation {
+    pub returns: Vec<ReturnSpec>,
+    pub return_overrides: Vec<ReturnSpec>,
+    pub body: Option<BodySpec>,
+    pub params: Vec<ParamSpec>,
+    pub security: Vec<String>,
+    /// The route this operation was registered at by `#[controller]` or
+    /// `#[resource]`, filled in automatically; empty if the endpoint wasn't
+    /// registered under either (e.g. a bare function using `#[openapi]`).
+    pub route: String,
+}
+
+/// Checks that every `path(...)` parameter declared in `params` has a
+/// matching `<name>` segment in `route`, returning the ones that don't.
+pub fn mismatched_path_params<'a>(route: &str, params: &'a [ParamSpec]) -> Vec<&'a ParamSpec> {
+    let segments: Vec<&str> =
+        route.split('/').filter_map(|seg| seg.strip_prefix('<').and_then(|s| s.strip_suffix('>'))).collect();
+    params
+        .iter()
+        .filter(|p| p.location == ParamLocation::Path)
+        .filter(|p| !segments.contains(&p.name.as_str()))
+        .collect()
+}
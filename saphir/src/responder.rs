@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+/// A minimal stand-in for the inbound request data an endpoint or guard can
+/// inspect. Real request/connection handling lives in saphir's HTTP runtime;
+/// this only carries what the macro-generated code needs (header lookups).
+#[derive(Debug, Default, Clone)]
+pub struct HttpContext {
+    pub headers: HashMap<String, String>,
+}
+
+impl HttpContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+/// Accumulates the status code, headers and body of a response as it's built
+/// up by a `Responder` chain.
+#[derive(Debug, Default, Clone)]
+pub struct Builder {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder { status: 200, headers: Vec::new(), body: Vec::new() }
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body<B: Into<Vec<u8>>>(mut self, body: B) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// Anything that can render itself into a response through a `Builder`.
+pub trait Responder {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder;
+}
+
+/// Wraps a serializable value, rendering it as a `application/json` body.
+pub struct Json<T>(pub T);
+
+impl<T: serde::Serialize> Responder for Json<T> {
+    fn respond_with_builder(self, builder: Builder, _ctx: &HttpContext) -> Builder {
+        let payload = serde_json::to_vec(&self.0).unwrap_or_default();
+        builder.header("Content-Type", "application/json").body(payload)
+    }
+}
+
+impl Responder for String {
+    fn respond_with_builder(self, builder: Builder, _ctx: &HttpContext) -> Builder {
+        builder.header("Content-Type", "text/plain").body(self.into_bytes())
+    }
+}
+
+impl Responder for &'static str {
+    fn respond_with_builder(self, builder: Builder, _ctx: &HttpContext) -> Builder {
+        builder.header("Content-Type", "text/plain").body(self.as_bytes().to_vec())
+    }
+}
+
+impl Responder for () {
+    fn respond_with_builder(self, builder: Builder, _ctx: &HttpContext) -> Builder {
+        builder
+    }
+}
+
+impl<T: Responder> Responder for Option<T> {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder {
+        match self {
+            Some(r) => r.respond_with_builder(builder, ctx),
+            None => builder.status(404),
+        }
+    }
+}
+
+impl<T: Responder, E: Responder> Responder for Result<T, E> {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder {
+        match self {
+            Ok(r) => r.respond_with_builder(builder.status(200), ctx),
+            Err(e) => e.respond_with_builder(builder.status(500), ctx),
+        }
+    }
+}
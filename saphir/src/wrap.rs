@@ -0,0 +1,23 @@
+use crate::responder::{Builder, HttpContext, Responder};
+
+/// Produced by `#[status(<code>)]`: wraps the endpoint's responder, applying
+/// `<code>` as the final status once the inner responder has run.
+pub struct StatusWrap<T>(pub T, pub u16);
+
+impl<T: Responder> Responder for StatusWrap<T> {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder {
+        let builder = self.0.respond_with_builder(builder, ctx);
+        builder.status(self.1)
+    }
+}
+
+/// Produced by `#[header(<name>, <value>)]`: wraps the endpoint's responder,
+/// setting a header once the inner responder has run.
+pub struct HeaderWrap<T>(pub T, pub &'static str, pub &'static str);
+
+impl<T: Responder> Responder for HeaderWrap<T> {
+    fn respond_with_builder(self, builder: Builder, ctx: &HttpContext) -> Builder {
+        let builder = self.0.respond_with_builder(builder, ctx);
+        builder.header(self.1, self.2)
+    }
+}
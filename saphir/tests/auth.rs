@@ -0,0 +1,109 @@
+use jsonwebtoken::{encode, EncodingKey, Header};
+use saphir::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MyClaims {
+    sub: String,
+    scopes: Vec<String>,
+}
+
+impl Scoped for MyClaims {
+    fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExpiringClaims {
+    sub: String,
+    scopes: Vec<String>,
+    exp: usize,
+}
+
+impl Scoped for ExpiringClaims {
+    fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
+
+struct AdminController;
+
+impl AdminController {
+    #[auth(claims = "MyClaims", scopes = ["admin"])]
+    async fn protected(&self, ctx: &HttpContext, claims: MyClaims) -> String {
+        format!("hello {}", claims.sub)
+    }
+
+    #[auth(claims = "ExpiringClaims", scopes = ["admin"])]
+    async fn protected_with_exp(&self, ctx: &HttpContext, claims: ExpiringClaims) -> String {
+        format!("hello {}", claims.sub)
+    }
+}
+
+fn token_for(scopes: &[&str]) -> String {
+    let claims = MyClaims { sub: "alice".to_string(), scopes: scopes.iter().map(|s| s.to_string()).collect() };
+    encode(&Header::default(), &claims, &EncodingKey::from_secret(b"saphir-dev-secret")).unwrap()
+}
+
+fn token_with_exp(scopes: &[&str], exp: usize) -> String {
+    let claims =
+        ExpiringClaims { sub: "alice".to_string(), scopes: scopes.iter().map(|s| s.to_string()).collect(), exp };
+    encode(&Header::default(), &claims, &EncodingKey::from_secret(b"saphir-dev-secret")).unwrap()
+}
+
+#[tokio::test]
+async fn auth_allows_valid_token_with_required_scope() {
+    let controller = AdminController;
+    let token = token_for(&["admin"]);
+    let ctx = HttpContext::new().header("Authorization", &format!("Bearer {token}"));
+    let outcome = controller.protected(&ctx).await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 200);
+    assert_eq!(builder.body, b"hello alice".to_vec());
+}
+
+#[tokio::test]
+async fn auth_rejects_missing_token_with_401() {
+    let controller = AdminController;
+    let ctx = HttpContext::new();
+    let outcome = controller.protected(&ctx).await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 401);
+}
+
+#[tokio::test]
+async fn auth_rejects_missing_scope_with_403() {
+    let controller = AdminController;
+    let token = token_for(&["user"]);
+    let ctx = HttpContext::new().header("Authorization", &format!("Bearer {token}"));
+    let outcome = controller.protected(&ctx).await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 403);
+}
+
+#[test]
+fn auth_documents_bearer_security_requirement() {
+    assert_eq!(AdminController::__openapi_auth_security_protected(), "bearerJWT");
+}
+
+#[tokio::test]
+async fn auth_rejects_expired_token_with_401() {
+    let controller = AdminController;
+    let token = token_with_exp(&["admin"], 1); // 1970-01-01T00:00:01Z, long expired
+    let ctx = HttpContext::new().header("Authorization", &format!("Bearer {token}"));
+    let outcome = controller.protected_with_exp(&ctx).await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 401);
+}
+
+#[tokio::test]
+async fn auth_allows_unexpired_token_with_exp_claim() {
+    let controller = AdminController;
+    let token = token_with_exp(&["admin"], 4102444800); // 2100-01-01T00:00:00Z
+    let ctx = HttpContext::new().header("Authorization", &format!("Bearer {token}"));
+    let outcome = controller.protected_with_exp(&ctx).await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 200);
+    assert_eq!(builder.body, b"hello alice".to_vec());
+}
@@ -0,0 +1,32 @@
+use saphir::prelude::*;
+
+struct UsersController;
+
+#[controller(name = "users")]
+impl UsersController {
+    #[get("/<user_id>")]
+    async fn read(&self, user_id: u32) -> String {
+        format!("user {user_id}")
+    }
+
+    #[post("/")]
+    async fn create(&self) -> &'static str {
+        "created"
+    }
+}
+
+#[test]
+fn controller_registers_explicit_endpoints() {
+    assert_eq!(UsersController::base_path(), "/users");
+    let endpoints = UsersController::endpoints();
+    assert_eq!(endpoints.len(), 2);
+    assert!(endpoints.iter().any(|e| e.method == Method::Get && e.path == "/users/<user_id>"));
+    assert!(endpoints.iter().any(|e| e.method == Method::Post && e.path == "/users"));
+}
+
+#[tokio::test]
+async fn controller_endpoints_still_run_as_plain_methods() {
+    let controller = UsersController;
+    assert_eq!(controller.read(42).await, "user 42");
+    assert_eq!(controller.create().await, "created");
+}
@@ -0,0 +1,39 @@
+use saphir::prelude::*;
+
+struct AdminController {
+    allow: bool,
+}
+
+fn admin_guard(controller: &AdminController, _data: &()) -> Result<(), u16> {
+    if controller.allow {
+        Ok(())
+    } else {
+        Err(403)
+    }
+}
+
+impl AdminController {
+    #[guard(fn = "admin_guard")]
+    async fn protected(&self) -> &'static str {
+        "admin area"
+    }
+}
+
+#[tokio::test]
+async fn guard_allows_when_check_passes() {
+    let controller = AdminController { allow: true };
+    let ctx = HttpContext::new();
+    let outcome = controller.protected().await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 200);
+    assert_eq!(builder.body, b"admin area".to_vec());
+}
+
+#[tokio::test]
+async fn guard_rejects_when_check_fails() {
+    let controller = AdminController { allow: false };
+    let ctx = HttpContext::new();
+    let outcome = controller.protected().await;
+    let builder = outcome.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 403);
+}
@@ -0,0 +1,149 @@
+use saphir::openapi::{mismatched_path_params, ParamLocation};
+use saphir::prelude::*;
+
+struct DocsController;
+
+#[controller(name = "docs")]
+impl DocsController {
+    #[get("/")]
+    #[openapi(security("bearerJWT"))]
+    #[openapi(return(code = 200, type = "String", mime = "text/plain"))]
+    async fn list(&self) -> String {
+        "docs".to_string()
+    }
+
+    #[get("/<user_id>")]
+    #[openapi(body(type = "Json<CreateUser>"))]
+    #[openapi(params(
+        query(name = "page", type = "u32", required = false),
+        path(name = "user_id", type = "u64")
+    ))]
+    async fn create(&self, user_id: u32) -> String {
+        format!("user {user_id}")
+    }
+}
+
+struct SchemeController;
+
+#[openapi(security_scheme(name = "bearerJWT", type = "http", scheme = "bearer", bearer_format = "JWT"))]
+#[controller(name = "scheme")]
+impl SchemeController {
+    #[get("/")]
+    async fn list(&self) -> &'static str {
+        "ok"
+    }
+}
+
+#[test]
+fn openapi_security_is_recorded_on_the_operation() {
+    let op = DocsController::__openapi_meta_list();
+    assert_eq!(op.security, vec!["bearerJWT".to_string()]);
+    assert_eq!(op.returns.len(), 1);
+    assert_eq!(op.returns[0].codes, vec![200]);
+    assert_eq!(op.returns[0].ty, "String");
+}
+
+#[test]
+fn controller_level_security_scheme_is_recorded() {
+    let schemes = SchemeController::__openapi_controller_security_schemes();
+    assert_eq!(schemes.len(), 1);
+    assert_eq!(schemes[0].name, "bearerJWT");
+    assert_eq!(schemes[0].scheme_type, "http");
+    assert_eq!(schemes[0].scheme.as_deref(), Some("bearer"));
+    assert_eq!(schemes[0].bearer_format.as_deref(), Some("JWT"));
+}
+
+struct TenantScopedController;
+
+#[openapi(param(name = "tenant_id", type = "u64"))]
+#[controller(prefix = "/tenants/<tenant_id>", name = "widgets")]
+impl TenantScopedController {
+    #[get("/")]
+    async fn list(&self, tenant_id: u64) -> String {
+        format!("widgets for tenant {tenant_id}")
+    }
+}
+
+#[test]
+fn controller_with_dynamic_prefix_keeps_the_segment_in_its_routes() {
+    assert_eq!(TenantScopedController::base_path(), "/tenants/<tenant_id>/widgets");
+    let endpoints = TenantScopedController::endpoints();
+    assert_eq!(endpoints.len(), 1);
+    assert_eq!(endpoints[0].path, "/tenants/<tenant_id>/widgets");
+}
+
+#[test]
+fn controller_level_shared_param_is_recorded() {
+    let params = TenantScopedController::__openapi_controller_params();
+    assert_eq!(params.len(), 1);
+    assert_eq!(params[0].name, "tenant_id");
+    assert_eq!(params[0].ty, "u64");
+    assert_eq!(params[0].location, ParamLocation::Path);
+    assert!(params[0].required);
+}
+
+#[test]
+fn controller_level_shared_param_is_merged_into_every_endpoint() {
+    let op = TenantScopedController::__openapi_meta_list();
+    assert_eq!(op.params.len(), 1);
+    assert_eq!(op.params[0].name, "tenant_id");
+    assert_eq!(op.params[0].ty, "u64");
+    assert_eq!(op.params[0].location, ParamLocation::Path);
+}
+
+#[test]
+fn openapi_body_and_params_are_recorded_on_the_operation() {
+    let op = DocsController::__openapi_meta_create();
+    let body = op.body.expect("body spec");
+    assert_eq!(body.ty, "Json<CreateUser>");
+    assert_eq!(op.params.len(), 2);
+    let page = op.params.iter().find(|p| p.name == "page").unwrap();
+    assert_eq!(page.location, ParamLocation::Query);
+    assert!(!page.required);
+    let user_id = op.params.iter().find(|p| p.name == "user_id").unwrap();
+    assert_eq!(user_id.location, ParamLocation::Path);
+    assert!(user_id.required);
+}
+
+#[test]
+fn mismatched_path_params_are_detected() {
+    let op = DocsController::__openapi_meta_create();
+    assert!(mismatched_path_params("/docs/<user_id>", &op.params).is_empty());
+    assert_eq!(mismatched_path_params("/docs/<id>", &op.params).len(), 1);
+}
+
+struct MismatchController;
+
+#[controller(name = "mismatch")]
+impl MismatchController {
+    #[get("/<id>")]
+    #[openapi(params(path(name = "wrong_name", type = "u64")))]
+    async fn show(&self, id: u32) -> String {
+        format!("item {id}")
+    }
+}
+
+#[test]
+fn operation_records_its_registered_route_automatically() {
+    let op = DocsController::__openapi_meta_create();
+    assert_eq!(op.route, "/docs/<user_id>");
+    assert!(mismatched_path_params(&op.route, &op.params).is_empty());
+}
+
+#[test]
+fn mismatched_path_param_is_detected_against_the_real_registered_route() {
+    let op = MismatchController::__openapi_meta_show();
+    assert_eq!(op.route, "/mismatch/<id>");
+    let mismatches = mismatched_path_params(&op.route, &op.params);
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].name, "wrong_name");
+}
+
+#[tokio::test]
+async fn docs_endpoints_still_run_as_plain_methods() {
+    assert_eq!(DocsController.list().await, "docs");
+    assert_eq!(DocsController.create(7).await, "user 7");
+    assert_eq!(SchemeController.list().await, "ok");
+    assert_eq!(TenantScopedController.list(42).await, "widgets for tenant 42");
+    assert_eq!(MismatchController.show(3).await, "item 3");
+}
@@ -0,0 +1,49 @@
+use saphir::prelude::*;
+
+struct UserResource;
+
+#[resource(path = "/users")]
+impl UserResource {
+    async fn read_all(&self) -> String {
+        "all users".to_string()
+    }
+
+    async fn read(&self, id: u32) -> String {
+        format!("user {id}")
+    }
+
+    async fn create(&self) -> &'static str {
+        "created"
+    }
+
+    async fn delete(&self, id: u32) -> String {
+        format!("deleted {id}")
+    }
+}
+
+#[test]
+fn resource_derives_crud_endpoints_from_method_names() {
+    assert_eq!(UserResource::base_path(), "/users");
+    let endpoints = UserResource::endpoints();
+    assert_eq!(endpoints.len(), 4);
+    assert!(endpoints.iter().any(|e| e.method == Method::Get && e.path == "/users"));
+    assert!(endpoints.iter().any(|e| e.method == Method::Get && e.path == "/users/<id>"));
+    assert!(endpoints.iter().any(|e| e.method == Method::Post && e.path == "/users"));
+    assert!(endpoints.iter().any(|e| e.method == Method::Delete && e.path == "/users/<id>"));
+}
+
+#[test]
+fn resource_skips_unimplemented_conventional_methods() {
+    let endpoints = UserResource::endpoints();
+    assert!(!endpoints.iter().any(|e| e.path == "/users/search"));
+    assert!(!endpoints.iter().any(|e| e.method == Method::Put));
+}
+
+#[tokio::test]
+async fn resource_methods_still_run_as_plain_methods() {
+    let resource = UserResource;
+    assert_eq!(resource.read_all().await, "all users");
+    assert_eq!(resource.read(7).await, "user 7");
+    assert_eq!(resource.create().await, "created");
+    assert_eq!(resource.delete(7).await, "deleted 7");
+}
@@ -0,0 +1,80 @@
+use saphir::prelude::*;
+
+struct ThingsController;
+
+#[controller(name = "things")]
+impl ThingsController {
+    #[post("/")]
+    #[status(201)]
+    async fn create(&self) -> &'static str {
+        "created"
+    }
+
+    #[post("/with-location")]
+    #[status(201)]
+    #[header("Location", "/things/1")]
+    async fn create_with_location(&self) -> &'static str {
+        "created"
+    }
+
+    #[post("/documented")]
+    #[status(201)]
+    #[openapi(security("bearerJWT"))]
+    async fn create_documented(&self) -> &'static str {
+        "created"
+    }
+
+    #[post("/documented-explicit")]
+    #[status(201)]
+    #[openapi(return(code = 200, type = "String", mime = "text/plain"))]
+    async fn create_documented_explicit(&self) -> &'static str {
+        "created"
+    }
+}
+
+#[tokio::test]
+async fn status_overrides_the_response_code() {
+    let ctx = HttpContext::new();
+    let builder = ThingsController.create().await.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 201);
+    assert_eq!(builder.body, b"created".to_vec());
+}
+
+#[tokio::test]
+async fn status_and_header_can_be_stacked() {
+    let ctx = HttpContext::new();
+    let builder = ThingsController.create_with_location().await.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 201);
+    assert!(builder.headers.iter().any(|(name, value)| name == "Location" && value == "/things/1"));
+}
+
+#[test]
+fn status_feeds_the_default_openapi_return_code() {
+    assert_eq!(ThingsController::__openapi_default_status_create(), 201);
+}
+
+#[test]
+fn status_automatically_documents_its_code_as_the_default_return() {
+    let op = ThingsController::__openapi_meta_create_documented();
+    assert_eq!(op.returns.len(), 1);
+    assert_eq!(op.returns[0].codes, vec![201]);
+}
+
+#[test]
+fn status_does_not_override_an_explicit_openapi_return() {
+    let op = ThingsController::__openapi_meta_create_documented_explicit();
+    assert_eq!(op.returns.len(), 1);
+    assert_eq!(op.returns[0].codes, vec![200]);
+}
+
+#[tokio::test]
+async fn documented_endpoints_still_run_as_plain_methods() {
+    let ctx = HttpContext::new();
+    let builder = ThingsController.create_documented().await.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 201);
+    assert_eq!(builder.body, b"created".to_vec());
+
+    let builder = ThingsController.create_documented_explicit().await.respond_with_builder(Builder::new(), &ctx);
+    assert_eq!(builder.status, 201);
+    assert_eq!(builder.body, b"created".to_vec());
+}
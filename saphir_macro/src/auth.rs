@@ -0,0 +1,108 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ItemFn, Pat, Path, ReturnType};
+
+struct AuthArgs {
+    claims: Path,
+    scopes: Vec<String>,
+}
+
+fn parse_args(attr: TokenStream) -> syn::Result<AuthArgs> {
+    let mut claims = None;
+    let mut scopes = Vec::new();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("claims") {
+            let value: syn::LitStr = meta.value()?.parse()?;
+            claims = Some(value.parse::<Path>()?);
+            Ok(())
+        } else if meta.path.is_ident("scopes") {
+            let array: syn::ExprArray = meta.value()?.parse()?;
+            for elem in array.elems {
+                if let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) = elem {
+                    scopes.push(s.value());
+                } else {
+                    return Err(syn::Error::new_spanned(elem, "expected a string literal scope"));
+                }
+            }
+            Ok(())
+        } else {
+            Err(meta.error("unsupported #[auth] argument"))
+        }
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+    let claims = claims.ok_or_else(|| syn::Error::new(proc_macro2::Span::call_site(), "#[auth] requires a `claims = \"...\"` argument"))?;
+    Ok(AuthArgs { claims, scopes })
+}
+
+/// Expands `#[auth(claims = "...", scopes = [...])]` into a JWT-validating
+/// guard: it extracts and decodes the bearer token found on the `ctx`
+/// parameter, rejects with 401/403 as needed, and binds the decoded claims
+/// to the endpoint's `claims` parameter.
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match parse_args(attr) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemFn);
+
+    if !input.sig.inputs.iter().any(|arg| matches!(arg, FnArg::Typed(t) if matches!(&*t.pat, Pat::Ident(i) if i.ident == "ctx")))
+    {
+        let err = syn::Error::new_spanned(&input.sig, "#[auth(...)] requires a `ctx: &HttpContext` parameter");
+        return err.to_compile_error().into();
+    }
+
+    let claims_idx =
+        input.sig.inputs.iter().position(|arg| matches!(arg, FnArg::Typed(t) if matches!(&*t.pat, Pat::Ident(i) if i.ident == "claims")));
+    let Some(claims_idx) = claims_idx else {
+        let err = syn::Error::new_spanned(&input.sig, "#[auth(...)] requires a `claims: <ClaimsType>` parameter to bind the decoded token into");
+        return err.to_compile_error().into();
+    };
+    input.sig.inputs = input
+        .sig
+        .inputs
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| *i != claims_idx)
+        .map(|(_, arg)| arg)
+        .collect();
+
+    let ret_ty = match &input.sig.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => syn::parse_quote!(()),
+    };
+    input.sig.output = syn::parse_quote!(-> ::saphir::guard::GuardOutcome<#ret_ty>);
+
+    let claims_ty = &args.claims;
+    let scopes = &args.scopes;
+    let original_block = input.block;
+    input.block = syn::parse_quote!({
+        let claims: #claims_ty = match ::saphir::auth::decode_bearer::<#claims_ty>(ctx) {
+            ::std::result::Result::Ok(c) => c,
+            ::std::result::Result::Err(_) => return ::saphir::guard::GuardOutcome::Rejected(401),
+        };
+        if !::saphir::auth::has_scopes(&claims, &[#(#scopes),*]) {
+            return ::saphir::guard::GuardOutcome::Rejected(403);
+        }
+        ::saphir::guard::GuardOutcome::Allowed(#original_block)
+    });
+
+    // Same trick as `#[status]`: when listed above a sibling `#[openapi(...)]`,
+    // ride along as an extra item so the endpoint's operation records the
+    // `bearerJWT` requirement too.
+    if input.attrs.iter().any(|a| a.path().is_ident("openapi")) {
+        input.attrs.push(syn::parse_quote!(#[openapi(security("bearerJWT"))]));
+    }
+
+    let security_fn_name = format_ident!("__openapi_auth_security_{}", input.sig.ident);
+    let vis = &input.vis;
+
+    quote! {
+        #input
+
+        #[allow(non_snake_case)]
+        #vis fn #security_fn_name() -> &'static str {
+            "bearerJWT"
+        }
+    }
+    .into()
+}
@@ -0,0 +1,199 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::{Attribute, FnArg, ImplItemFn, Pat};
+
+/// The HTTP verb a `#[get]`/`#[post]`/... marker attribute declares.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MethodKind {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Patch,
+    Any,
+}
+
+impl MethodKind {
+    pub fn from_ident(ident: &str) -> Option<Self> {
+        match ident {
+            "get" => Some(MethodKind::Get),
+            "post" => Some(MethodKind::Post),
+            "put" => Some(MethodKind::Put),
+            "delete" => Some(MethodKind::Delete),
+            "patch" => Some(MethodKind::Patch),
+            "any" => Some(MethodKind::Any),
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for MethodKind {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let variant = match self {
+            MethodKind::Get => quote! { Get },
+            MethodKind::Post => quote! { Post },
+            MethodKind::Put => quote! { Put },
+            MethodKind::Delete => quote! { Delete },
+            MethodKind::Patch => quote! { Patch },
+            MethodKind::Any => quote! { Any },
+        };
+        tokens.extend(quote! { ::saphir::controller::Method::#variant });
+    }
+}
+
+/// One endpoint derived either from an explicit `#[get(...)]` marker or from
+/// `#[resource]`'s naming convention.
+pub struct EndpointDef {
+    pub method: MethodKind,
+    pub path: String,
+}
+
+/// Removes a `#[get("/path")]`-style marker attribute from `attrs`, if
+/// present, returning the method/path pair it declared. Endpoint markers are
+/// not real proc-macros: `#[controller]` sees them as raw tokens on the impl
+/// block it's attached to and strips them before the impl is handed back to
+/// the compiler, so they never need to resolve on their own. `#[resource]`
+/// derives routes from method names and does not strip them.
+pub fn take_method_marker(attrs: &mut Vec<Attribute>) -> Option<(MethodKind, String)> {
+    let idx = attrs
+        .iter()
+        .position(|attr| attr.path().get_ident().and_then(|i| MethodKind::from_ident(&i.to_string())).is_some())?;
+    let attr = attrs.remove(idx);
+    let kind = MethodKind::from_ident(&attr.path().get_ident().unwrap().to_string()).unwrap();
+    let path = attr
+        .parse_args::<syn::LitStr>()
+        .map(|lit| lit.value())
+        .unwrap_or_else(|_| "/".to_string());
+    Some((kind, path))
+}
+
+/// If `method` carries an `#[openapi(...)]` attribute, appends a hidden
+/// `#[openapi(__route = "<route>")]` attribute carrying its fully-joined
+/// route. `collect_openapi_items` merges every `#[openapi(...)]` attribute on
+/// a function together, so this rides along and lets the `#[openapi]`
+/// expansion on that method cross-check its declared `path(...)` params
+/// against the route actually registered for it, which it otherwise has no
+/// way to see.
+pub fn inject_openapi_route(method: &mut ImplItemFn, route: &str) {
+    if method.attrs.iter().any(|a| a.path().is_ident("openapi")) {
+        method.attrs.push(syn::parse_quote!(#[openapi(__route = #route)]));
+    }
+}
+
+/// A controller-level parameter declared via `#[openapi(param(...))]` on an
+/// `impl` block, e.g. the `tenant_id` of a `prefix = "/tenants/<tenant_id>"`.
+pub struct SharedParam {
+    pub name: String,
+    pub ty: String,
+}
+
+fn parse_shared_param_fields(input: syn::parse::ParseStream) -> syn::Result<SharedParam> {
+    let mut name = String::new();
+    let mut ty = String::new();
+    while !input.is_empty() {
+        let ident = syn::Ident::parse_any(input)?;
+        input.parse::<syn::Token![=]>()?;
+        let lit: syn::LitStr = input.parse()?;
+        match ident.to_string().as_str() {
+            "name" => name = lit.value(),
+            "type" => ty = lit.value(),
+            other => return Err(syn::Error::new(ident.span(), format!("unsupported shared param field `{other}`"))),
+        }
+        if input.is_empty() {
+            break;
+        }
+        input.parse::<syn::Token![,]>()?;
+    }
+    Ok(SharedParam { name, ty })
+}
+
+/// Removes every hidden `#[saphir_shared_param(name = "...", type = "...")]`
+/// marker from `attrs`, returning the controller-level shared params they
+/// carried. `openapi::expand`'s `Item::Impl` branch leaves these behind (it
+/// must run before `#[controller]`, i.e. be listed above it, since it's the
+/// one that actually parses `#[openapi(param(...))]`) so `#[controller]` can
+/// require and document the parameter on every endpoint it registers.
+pub fn take_shared_params(attrs: &mut Vec<Attribute>) -> Vec<SharedParam> {
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < attrs.len() {
+        if attrs[i].path().is_ident("saphir_shared_param") {
+            let attr = attrs.remove(i);
+            if let Ok(parsed) = attr.parse_args_with(parse_shared_param_fields) {
+                out.push(parsed);
+            }
+        } else {
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Checks that `method` declares a parameter named after `shared.name`,
+/// returning a compile error pointing at its signature if not. This is how a
+/// controller-level shared param becomes "available to each handler's
+/// signature": the compiler enforces every endpoint declares it, the same way
+/// `#[auth]` requires its `ctx`/`claims` parameters rather than inventing a
+/// request-extraction layer this crate doesn't have.
+pub fn require_shared_param_in_signature(method: &ImplItemFn, shared: &SharedParam) -> Option<syn::Error> {
+    let declared = method.sig.inputs.iter().any(|arg| match arg {
+        FnArg::Typed(pat_type) => matches!(&*pat_type.pat, Pat::Ident(pat_ident) if pat_ident.ident == shared.name),
+        FnArg::Receiver(_) => false,
+    });
+    if declared {
+        None
+    } else {
+        Some(syn::Error::new_spanned(
+            &method.sig,
+            format!(
+                "endpoint `{}` must declare a `{}: {}` parameter: its controller's prefix carries a shared `{}` \
+                 segment documented via #[openapi(param(...))]",
+                method.sig.ident, shared.name, shared.ty, shared.name
+            ),
+        ))
+    }
+}
+
+/// Appends a hidden `#[openapi(param(name = "...", type = "..."))]` attribute
+/// for `shared` onto `method`, merging it into that endpoint's own OpenAPI
+/// metadata the same way any other `#[openapi(...)]` attribute would.
+pub fn inject_shared_param(method: &mut ImplItemFn, shared: &SharedParam) {
+    let name = &shared.name;
+    let ty = &shared.ty;
+    method.attrs.push(syn::parse_quote!(#[openapi(param(name = #name, type = #ty))]));
+}
+
+/// Joins a controller prefix and an endpoint path into a single route,
+/// normalizing the slash between them.
+pub fn join_path(prefix: &str, path: &str) -> String {
+    let prefix = prefix.trim_end_matches('/');
+    let path = if path.starts_with('/') { path.to_string() } else { format!("/{path}") };
+    if prefix.is_empty() {
+        path
+    } else if path == "/" {
+        prefix.to_string()
+    } else {
+        format!("{prefix}{path}")
+    }
+}
+
+/// Emits the `Controller` impl shared by `#[controller]` and `#[resource]`.
+pub fn build_controller_impl(self_ty: &syn::Type, base_path: &str, endpoints: &[EndpointDef]) -> TokenStream {
+    let endpoint_tokens = endpoints.iter().map(|e| {
+        let method = e.method;
+        let path = &e.path;
+        quote! { ::saphir::controller::Endpoint { method: #method, path: #path.to_string() } }
+    });
+    quote! {
+        impl ::saphir::controller::Controller for #self_ty {
+            fn base_path() -> &'static str {
+                #base_path
+            }
+
+            fn endpoints() -> ::std::vec::Vec<::saphir::controller::Endpoint> {
+                vec![ #(#endpoint_tokens),* ]
+            }
+        }
+    }
+}
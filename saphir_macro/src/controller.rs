@@ -0,0 +1,101 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, LitInt, LitStr};
+
+use crate::common::{
+    build_controller_impl, inject_openapi_route, inject_shared_param, join_path, require_shared_param_in_signature,
+    take_method_marker, take_shared_params,
+};
+
+#[derive(Default)]
+struct ControllerArgs {
+    prefix: Option<String>,
+    version: Option<u16>,
+    name: Option<String>,
+}
+
+fn parse_args(attr: TokenStream) -> syn::Result<ControllerArgs> {
+    let mut args = ControllerArgs::default();
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("prefix") {
+            let value: LitStr = meta.value()?.parse()?;
+            args.prefix = Some(value.value());
+            Ok(())
+        } else if meta.path.is_ident("version") {
+            let value: LitInt = meta.value()?.parse()?;
+            args.version = Some(value.base10_parse()?);
+            Ok(())
+        } else if meta.path.is_ident("name") {
+            let value: LitStr = meta.value()?.parse()?;
+            args.name = Some(value.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported #[controller] argument"))
+        }
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+    Ok(args)
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match parse_args(attr) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemImpl);
+
+    let default_name = match &*input.self_ty {
+        syn::Type::Path(p) => p
+            .path
+            .segments
+            .last()
+            .map(|seg| seg.ident.to_string().to_lowercase().trim_end_matches("controller").to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+    let name = args.name.unwrap_or(default_name);
+
+    // A prefix may itself carry a dynamic segment (e.g. "/tenants/<tenant_id>"):
+    // it's kept verbatim here and documented once via a controller-level
+    // `#[openapi(param(...))]`, instead of being repeated on every endpoint.
+    let mut base_path = args.prefix.unwrap_or_default();
+    if let Some(version) = args.version {
+        base_path = format!("{}/v{}", base_path.trim_end_matches('/'), version);
+    }
+    if !name.is_empty() {
+        base_path = join_path(&base_path, &name);
+    }
+
+    // Left behind by `#[openapi(param(...))]` on this same impl block (it must
+    // be listed above `#[controller]` to run first); each one gets merged into
+    // every endpoint's own OpenAPI metadata and required on every handler's
+    // signature below.
+    let shared_params = take_shared_params(&mut input.attrs);
+
+    let mut endpoints = Vec::new();
+    for impl_item in input.items.iter_mut() {
+        if let syn::ImplItem::Fn(method) = impl_item {
+            if let Some((kind, path)) = take_method_marker(&mut method.attrs) {
+                let route = join_path(&base_path, &path);
+                for shared in &shared_params {
+                    if let Some(err) = require_shared_param_in_signature(method, shared) {
+                        return err.to_compile_error().into();
+                    }
+                    inject_shared_param(method, shared);
+                }
+                inject_openapi_route(method, &route);
+                endpoints.push(crate::common::EndpointDef { method: kind, path: route });
+            }
+        }
+    }
+
+    let self_ty = (*input.self_ty).clone();
+    let controller_impl = build_controller_impl(&self_ty, &base_path, &endpoints);
+
+    let output = quote! {
+        #input
+
+        #controller_impl
+    };
+    output.into()
+}
@@ -0,0 +1,60 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemFn, Path, ReturnType};
+
+struct GuardArgs {
+    guard_fn: Path,
+    data: Option<Path>,
+}
+
+fn parse_args(attr: TokenStream) -> syn::Result<GuardArgs> {
+    let mut guard_fn = None;
+    let mut data = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("fn") {
+            let value: syn::LitStr = meta.value()?.parse()?;
+            guard_fn = Some(value.parse::<Path>()?);
+            Ok(())
+        } else if meta.path.is_ident("data") {
+            let value: syn::LitStr = meta.value()?.parse()?;
+            data = Some(value.parse::<Path>()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported #[guard] argument"))
+        }
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+    let guard_fn = guard_fn.ok_or_else(|| syn::Error::new(proc_macro2::Span::call_site(), "#[guard] requires a `fn = \"...\"` argument"))?;
+    Ok(GuardArgs { guard_fn, data })
+}
+
+/// Wraps an endpoint's body with a call to the declared guard function,
+/// rejecting the request (as a status code) before the endpoint body runs.
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match parse_args(attr) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemFn);
+
+    let ret_ty = match &input.sig.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => syn::parse_quote!(()),
+    };
+    input.sig.output = syn::parse_quote!(-> ::saphir::guard::GuardOutcome<#ret_ty>);
+
+    let guard_fn = &args.guard_fn;
+    let data_expr = match &args.data {
+        Some(path) => quote! { #path(self) },
+        None => quote! { () },
+    };
+    let original_block = input.block;
+    input.block = syn::parse_quote!({
+        if let ::std::result::Result::Err(__saphir_guard_status) = #guard_fn(self, &#data_expr) {
+            return ::saphir::guard::GuardOutcome::Rejected(__saphir_guard_status);
+        }
+        ::saphir::guard::GuardOutcome::Allowed(#original_block)
+    });
+
+    quote! { #input }.into()
+}
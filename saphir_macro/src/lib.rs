@@ -0,0 +1,56 @@
+//! Proc-macro attributes backing saphir's controllers, guards and OpenAPI
+//! documentation. See `saphir::macros` for the user-facing documentation of
+//! each attribute.
+
+use proc_macro::TokenStream;
+
+mod auth;
+mod common;
+mod controller;
+mod guard;
+mod openapi;
+mod resource;
+mod status_header;
+
+#[proc_macro_attribute]
+pub fn controller(attr: TokenStream, item: TokenStream) -> TokenStream {
+    controller::expand(attr, item)
+}
+
+#[proc_macro_attribute]
+pub fn resource(attr: TokenStream, item: TokenStream) -> TokenStream {
+    resource::expand(attr, item)
+}
+
+#[proc_macro_attribute]
+pub fn guard(attr: TokenStream, item: TokenStream) -> TokenStream {
+    guard::expand(attr, item)
+}
+
+#[proc_macro_attribute]
+pub fn auth(attr: TokenStream, item: TokenStream) -> TokenStream {
+    auth::expand(attr, item)
+}
+
+/// Middleware registration is handled by saphir's server-building code; this
+/// attribute currently only exists so `impl` blocks using it continue to
+/// parse, and is a pass-through no-op.
+#[proc_macro_attribute]
+pub fn middleware(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
+
+#[proc_macro_attribute]
+pub fn openapi(attr: TokenStream, item: TokenStream) -> TokenStream {
+    openapi::expand(attr, item)
+}
+
+#[proc_macro_attribute]
+pub fn status(attr: TokenStream, item: TokenStream) -> TokenStream {
+    status_header::expand_status(attr, item)
+}
+
+#[proc_macro_attribute]
+pub fn header(attr: TokenStream, item: TokenStream) -> TokenStream {
+    status_header::expand_header(attr, item)
+}
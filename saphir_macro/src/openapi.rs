@@ -0,0 +1,487 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, Item, Lit, LitStr, Token};
+
+#[derive(Default)]
+struct ReturnSpecAst {
+    codes: Vec<u16>,
+    ty: String,
+    mime: Option<String>,
+}
+
+struct BodySpecAst {
+    ty: String,
+    mime: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+enum ParamLocationAst {
+    Query,
+    Path,
+}
+
+struct ParamSpecAst {
+    name: String,
+    ty: String,
+    location: ParamLocationAst,
+    required: bool,
+}
+
+struct SecuritySchemeAst {
+    name: String,
+    scheme_type: String,
+    scheme: Option<String>,
+    bearer_format: Option<String>,
+}
+
+enum OpenApiItem {
+    Mime(String),
+    Return(ReturnSpecAst),
+    ReturnOverride(ReturnSpecAst),
+    Body(BodySpecAst),
+    Params(Vec<ParamSpecAst>),
+    Security(String),
+    SecurityScheme(SecuritySchemeAst),
+    Param(ParamSpecAst),
+    /// Not user-facing: injected by `common::inject_openapi_route` so the
+    /// endpoint's own `#[openapi(...)]` expansion knows the route it was
+    /// registered at, without the `#[controller]`/`#[resource]` macro having
+    /// to parse or rewrite the rest of its `#[openapi(...)]` attributes.
+    Route(String),
+    /// Not user-facing: injected by `status_header::expand_status` the same
+    /// way, so `#[status(<code>)]` can default the documented return code
+    /// without the endpoint also needing `#[openapi(return(code = ...))]`.
+    DefaultStatus(u16),
+}
+
+fn lit_to_u16(lit: &Lit) -> syn::Result<u16> {
+    match lit {
+        Lit::Int(i) => i.base10_parse(),
+        other => Err(syn::Error::new_spanned(other, "expected an integer")),
+    }
+}
+
+fn lit_to_string(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        other => Err(syn::Error::new_spanned(other, "expected a string")),
+    }
+}
+
+/// Reads a parenthesized, comma-separated `ident = literal` list.
+fn parse_kv_list(content: ParseStream) -> syn::Result<Vec<(String, Lit)>> {
+    let mut out = Vec::new();
+    while !content.is_empty() {
+        let ident = Ident::parse_any(content)?;
+        content.parse::<Token![=]>()?;
+        let lit: Lit = content.parse()?;
+        out.push((ident.to_string(), lit));
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    Ok(out)
+}
+
+fn parse_return_spec(content: ParseStream) -> syn::Result<ReturnSpecAst> {
+    let mut spec = ReturnSpecAst::default();
+    for (key, lit) in parse_kv_list(content)? {
+        match key.as_str() {
+            "code" => spec.codes.push(lit_to_u16(&lit)?),
+            "type" => spec.ty = lit_to_string(&lit)?,
+            "mime" => spec.mime = Some(lit_to_string(&lit)?),
+            other => return Err(syn::Error::new(Span::call_site(), format!("unsupported `return` field `{other}`"))),
+        }
+    }
+    Ok(spec)
+}
+
+fn parse_body_spec(content: ParseStream) -> syn::Result<BodySpecAst> {
+    let mut ty = String::new();
+    let mut mime = None;
+    for (key, lit) in parse_kv_list(content)? {
+        match key.as_str() {
+            "type" => ty = lit_to_string(&lit)?,
+            "mime" => mime = Some(lit_to_string(&lit)?),
+            other => return Err(syn::Error::new(Span::call_site(), format!("unsupported `body` field `{other}`"))),
+        }
+    }
+    Ok(BodySpecAst { ty, mime })
+}
+
+fn lit_to_bool(lit: &Lit) -> syn::Result<bool> {
+    match lit {
+        Lit::Bool(b) => Ok(b.value()),
+        other => Err(syn::Error::new_spanned(other, "expected a bool")),
+    }
+}
+
+fn parse_param_fields(content: ParseStream, location: ParamLocationAst) -> syn::Result<ParamSpecAst> {
+    let mut name = String::new();
+    let mut ty = String::new();
+    let mut required = true;
+    for (key, lit) in parse_kv_list(content)? {
+        match key.as_str() {
+            "name" => name = lit_to_string(&lit)?,
+            "type" => ty = lit_to_string(&lit)?,
+            "required" => required = lit_to_bool(&lit)?,
+            other => return Err(syn::Error::new(Span::call_site(), format!("unsupported param field `{other}`"))),
+        }
+    }
+    // Path parameters are always required regardless of what was declared.
+    if matches!(location, ParamLocationAst::Path) {
+        required = true;
+    }
+    Ok(ParamSpecAst { name, ty, location, required })
+}
+
+fn parse_params(content: ParseStream) -> syn::Result<Vec<ParamSpecAst>> {
+    let mut out = Vec::new();
+    while !content.is_empty() {
+        let ident = Ident::parse_any(content)?;
+        let inner;
+        syn::parenthesized!(inner in content);
+        let location = match ident.to_string().as_str() {
+            "query" => ParamLocationAst::Query,
+            "path" => ParamLocationAst::Path,
+            other => return Err(syn::Error::new(ident.span(), format!("unsupported param kind `{other}`"))),
+        };
+        out.push(parse_param_fields(&inner, location)?);
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    Ok(out)
+}
+
+fn parse_security_scheme(content: ParseStream) -> syn::Result<SecuritySchemeAst> {
+    let mut name = String::new();
+    let mut scheme_type = String::new();
+    let mut scheme = None;
+    let mut bearer_format = None;
+    for (key, lit) in parse_kv_list(content)? {
+        match key.as_str() {
+            "name" => name = lit_to_string(&lit)?,
+            "type" => scheme_type = lit_to_string(&lit)?,
+            "scheme" => scheme = Some(lit_to_string(&lit)?),
+            "bearer_format" => bearer_format = Some(lit_to_string(&lit)?),
+            other => return Err(syn::Error::new(Span::call_site(), format!("unsupported `security_scheme` field `{other}`"))),
+        }
+    }
+    Ok(SecuritySchemeAst { name, scheme_type, scheme, bearer_format })
+}
+
+impl Parse for OpenApiItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        let name = ident.to_string();
+        if name == "mime" {
+            input.parse::<Token![=]>()?;
+            let lit: LitStr = input.parse()?;
+            return Ok(OpenApiItem::Mime(lit.value()));
+        }
+        if name == "__route" {
+            input.parse::<Token![=]>()?;
+            let lit: LitStr = input.parse()?;
+            return Ok(OpenApiItem::Route(lit.value()));
+        }
+        if name == "__default_status" {
+            input.parse::<Token![=]>()?;
+            let lit: Lit = input.parse()?;
+            return Ok(OpenApiItem::DefaultStatus(lit_to_u16(&lit)?));
+        }
+        if name == "security" {
+            let content;
+            syn::parenthesized!(content in input);
+            let lit: LitStr = content.parse()?;
+            return Ok(OpenApiItem::Security(lit.value()));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        match name.as_str() {
+            "return" => Ok(OpenApiItem::Return(parse_return_spec(&content)?)),
+            "return_override" => Ok(OpenApiItem::ReturnOverride(parse_return_spec(&content)?)),
+            "body" => Ok(OpenApiItem::Body(parse_body_spec(&content)?)),
+            "params" => Ok(OpenApiItem::Params(parse_params(&content)?)),
+            "security_scheme" => Ok(OpenApiItem::SecurityScheme(parse_security_scheme(&content)?)),
+            "param" => Ok(OpenApiItem::Param(parse_param_fields(&content, ParamLocationAst::Path)?)),
+            other => Err(syn::Error::new(ident.span(), format!("unsupported #[openapi] item `{other}`"))),
+        }
+    }
+}
+
+struct OpenApiItems(Vec<OpenApiItem>);
+
+impl Parse for OpenApiItems {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let items = syn::punctuated::Punctuated::<OpenApiItem, Token![,]>::parse_terminated(input)?;
+        Ok(OpenApiItems(items.into_iter().collect()))
+    }
+}
+
+fn return_spec_tokens(spec: &ReturnSpecAst) -> proc_macro2::TokenStream {
+    let codes = &spec.codes;
+    let ty = &spec.ty;
+    let mime = opt_string_tokens(&spec.mime);
+    quote! {
+        ::saphir::openapi::ReturnSpec {
+            codes: vec![#(#codes),*],
+            ty: #ty.to_string(),
+            mime: #mime,
+        }
+    }
+}
+
+fn param_location_tokens(loc: ParamLocationAst) -> proc_macro2::TokenStream {
+    match loc {
+        ParamLocationAst::Query => quote! { ::saphir::openapi::ParamLocation::Query },
+        ParamLocationAst::Path => quote! { ::saphir::openapi::ParamLocation::Path },
+    }
+}
+
+fn param_spec_tokens(spec: &ParamSpecAst) -> proc_macro2::TokenStream {
+    let name = &spec.name;
+    let ty = &spec.ty;
+    let required = spec.required;
+    let location = param_location_tokens(spec.location);
+    quote! {
+        ::saphir::openapi::ParamSpec {
+            name: #name.to_string(),
+            ty: #ty.to_string(),
+            location: #location,
+            required: #required,
+        }
+    }
+}
+
+fn opt_string_tokens(value: &Option<String>) -> proc_macro2::TokenStream {
+    match value {
+        Some(v) => quote! { ::std::option::Option::Some(#v.to_string()) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+fn security_scheme_tokens(spec: &SecuritySchemeAst) -> proc_macro2::TokenStream {
+    let name = &spec.name;
+    let scheme_type = &spec.scheme_type;
+    let scheme = opt_string_tokens(&spec.scheme);
+    let bearer_format = opt_string_tokens(&spec.bearer_format);
+    quote! {
+        ::saphir::openapi::SecuritySchemeSpec {
+            name: #name.to_string(),
+            scheme_type: #scheme_type.to_string(),
+            scheme: #scheme,
+            bearer_format: #bearer_format,
+        }
+    }
+}
+
+/// Parses every `#[openapi(...)]` attribute still attached to `attrs`
+/// (in addition to the one already captured as `leading`), removing them so
+/// they aren't independently re-expanded, and returns the combined list of
+/// items they declared together.
+fn collect_openapi_items(leading: TokenStream, attrs: &mut Vec<syn::Attribute>) -> syn::Result<Vec<OpenApiItem>> {
+    let mut items = syn::parse::<OpenApiItems>(leading)?.0;
+    let mut i = 0;
+    while i < attrs.len() {
+        if attrs[i].path().is_ident("openapi") {
+            let attr = attrs.remove(i);
+            let parsed: OpenApiItems = attr.parse_args()?;
+            items.extend(parsed.0);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(items)
+}
+
+fn build_operation_tokens(items: &[OpenApiItem]) -> proc_macro2::TokenStream {
+    let mut stmts = Vec::new();
+    for item in items {
+        match item {
+            OpenApiItem::Return(spec) => {
+                let tokens = return_spec_tokens(spec);
+                stmts.push(quote! { op.returns.push(#tokens); });
+            }
+            OpenApiItem::ReturnOverride(spec) => {
+                let tokens = return_spec_tokens(spec);
+                stmts.push(quote! { op.return_overrides.push(#tokens); });
+            }
+            OpenApiItem::Body(spec) => {
+                let ty = &spec.ty;
+                let mime = opt_string_tokens(&spec.mime);
+                stmts.push(quote! {
+                    op.body = ::std::option::Option::Some(::saphir::openapi::BodySpec { ty: #ty.to_string(), mime: #mime });
+                });
+            }
+            OpenApiItem::Params(params) => {
+                for p in params {
+                    let tokens = param_spec_tokens(p);
+                    stmts.push(quote! { op.params.push(#tokens); });
+                }
+            }
+            OpenApiItem::Security(name) => {
+                stmts.push(quote! { op.security.push(#name.to_string()); });
+            }
+            OpenApiItem::Param(p) => {
+                let tokens = param_spec_tokens(p);
+                stmts.push(quote! { op.params.push(#tokens); });
+            }
+            OpenApiItem::Route(route) => {
+                stmts.push(quote! { op.route = #route.to_string(); });
+            }
+            OpenApiItem::SecurityScheme(_) | OpenApiItem::Mime(_) | OpenApiItem::DefaultStatus(_) => {}
+        }
+    }
+    // `#[status(<code>)]` only fills in a default return code when the endpoint
+    // hasn't already documented one explicitly via `return(...)` - it's a
+    // fallback, not an override.
+    let has_explicit_return = items.iter().any(|i| matches!(i, OpenApiItem::Return(_)));
+    if !has_explicit_return {
+        if let Some(code) = items.iter().find_map(|i| match i {
+            OpenApiItem::DefaultStatus(code) => Some(*code),
+            _ => None,
+        }) {
+            stmts.push(quote! {
+                op.returns.push(::saphir::openapi::ReturnSpec {
+                    codes: vec![#code],
+                    ty: ::std::string::String::new(),
+                    mime: ::std::option::Option::None,
+                });
+            });
+        }
+    }
+    quote! {
+        {
+            let mut op = ::saphir::openapi::OpenApiOperation::default();
+            #(#stmts)*
+            if !op.route.is_empty() {
+                for __saphir_mismatched_param in ::saphir::openapi::mismatched_path_params(&op.route, &op.params) {
+                    ::std::eprintln!(
+                        "saphir: #[openapi] path param `{}` has no matching `<...>` segment in registered route `{}`",
+                        __saphir_mismatched_param.name, op.route,
+                    );
+                }
+            }
+            op
+        }
+    }
+}
+
+/// `#[openapi(...)]` applies to three different kinds of items so far:
+/// - an endpoint method: accumulates into a hidden `__openapi_meta_*` fn
+/// - a `struct`/`enum`: documents its mime type via a hidden fn
+/// - a controller `impl` block: documents its security schemes
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let parsed_item = match syn::parse::<Item>(item.clone()) {
+        Ok(i) => i,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    match parsed_item {
+        Item::Fn(mut func) => {
+            let items = match collect_openapi_items(attr, &mut func.attrs) {
+                Ok(i) => i,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            let operation = build_operation_tokens(&items);
+            let meta_fn_name = format_ident!("__openapi_meta_{}", func.sig.ident);
+            let vis = &func.vis;
+            quote! {
+                #func
+
+                #[allow(non_snake_case)]
+                #vis fn #meta_fn_name() -> ::saphir::openapi::OpenApiOperation {
+                    #operation
+                }
+            }
+            .into()
+        }
+        Item::Struct(mut s) => {
+            let items = match collect_openapi_items(attr, &mut s.attrs) {
+                Ok(i) => i,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            emit_type_mime(&s.ident, &items, quote! { #s })
+        }
+        Item::Enum(mut e) => {
+            let items = match collect_openapi_items(attr, &mut e.attrs) {
+                Ok(i) => i,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            emit_type_mime(&e.ident, &items, quote! { #e })
+        }
+        Item::Impl(mut imp) => {
+            let items = match collect_openapi_items(attr, &mut imp.attrs) {
+                Ok(i) => i,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            let self_ty = &imp.self_ty;
+            let schemes: Vec<_> = items
+                .iter()
+                .filter_map(|i| match i {
+                    OpenApiItem::SecurityScheme(s) => Some(security_scheme_tokens(s)),
+                    _ => None,
+                })
+                .collect();
+            let params: Vec<_> = items
+                .iter()
+                .filter_map(|i| match i {
+                    OpenApiItem::Param(p) => Some(param_spec_tokens(p)),
+                    _ => None,
+                })
+                .collect();
+            // Leave a hidden marker behind for `#[controller]` (which must be
+            // listed below this attribute, so it expands after and sees these)
+            // to pick up: it merges each shared param into every endpoint's own
+            // OpenAPI metadata and requires a matching parameter on every
+            // handler's signature.
+            for item in &items {
+                if let OpenApiItem::Param(p) = item {
+                    let name = &p.name;
+                    let ty = &p.ty;
+                    imp.attrs.push(syn::parse_quote!(#[saphir_shared_param(name = #name, type = #ty)]));
+                }
+            }
+            quote! {
+                #imp
+
+                impl #self_ty {
+                    #[allow(non_snake_case)]
+                    pub fn __openapi_controller_security_schemes() -> ::std::vec::Vec<::saphir::openapi::SecuritySchemeSpec> {
+                        vec![ #(#schemes),* ]
+                    }
+
+                    #[allow(non_snake_case)]
+                    pub fn __openapi_controller_params() -> ::std::vec::Vec<::saphir::openapi::ParamSpec> {
+                        vec![ #(#params),* ]
+                    }
+                }
+            }
+            .into()
+        }
+        other => quote! { #other }.into(),
+    }
+}
+
+fn emit_type_mime(ident: &Ident, items: &[OpenApiItem], original: proc_macro2::TokenStream) -> TokenStream {
+    let mime = items
+        .iter()
+        .find_map(|i| if let OpenApiItem::Mime(m) = i { Some(m.clone()) } else { None })
+        .unwrap_or_default();
+    let fn_name = format_ident!("__openapi_type_mime_{}", ident);
+    quote! {
+        #original
+
+        #[allow(non_snake_case)]
+        fn #fn_name() -> &'static str {
+            #mime
+        }
+    }
+    .into()
+}
@@ -0,0 +1,73 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemImpl, LitStr};
+
+use crate::common::{build_controller_impl, inject_openapi_route, join_path, EndpointDef, MethodKind};
+
+struct ResourceArgs {
+    path: String,
+}
+
+fn parse_args(attr: TokenStream) -> syn::Result<ResourceArgs> {
+    let mut path = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("path") {
+            let value: LitStr = meta.value()?.parse()?;
+            path = Some(value.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported #[resource] argument"))
+        }
+    });
+    syn::parse::Parser::parse(parser, attr)?;
+    path.map(|path| ResourceArgs { path }).ok_or_else(|| syn::Error::new(proc_macro2::Span::call_site(), "#[resource] requires a `path = \"...\"` argument"))
+}
+
+/// Maps a conventionally-named CRUD method to its method + path, mirroring
+/// gotham_restful's `read_all`/`read`/`search`/`create`/`update_all`/
+/// `update`/`delete_all`/`delete` set.
+fn endpoint_for(fn_name: &str) -> Option<(MethodKind, &'static str)> {
+    match fn_name {
+        "read_all" => Some((MethodKind::Get, "")),
+        "read" => Some((MethodKind::Get, "/<id>")),
+        "search" => Some((MethodKind::Get, "/search")),
+        "create" => Some((MethodKind::Post, "")),
+        "update_all" => Some((MethodKind::Put, "")),
+        "update" => Some((MethodKind::Put, "/<id>")),
+        "delete_all" => Some((MethodKind::Delete, "")),
+        "delete" => Some((MethodKind::Delete, "/<id>")),
+        _ => None,
+    }
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match parse_args(attr) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemImpl);
+
+    let mut endpoints = Vec::new();
+    for impl_item in input.items.iter_mut() {
+        if let syn::ImplItem::Fn(method) = impl_item {
+            let fn_name = method.sig.ident.to_string();
+            if let Some((kind, suffix)) = endpoint_for(&fn_name) {
+                let route = join_path(&args.path, suffix);
+                inject_openapi_route(method, &route);
+                endpoints.push(EndpointDef { method: kind, path: route });
+            }
+        }
+    }
+
+    let self_ty = (*input.self_ty).clone();
+    // Reuses the same `Controller` codegen `#[controller]` emits: only the
+    // method/path derivation differs, not the registration itself.
+    let controller_impl = build_controller_impl(&self_ty, &args.path, &endpoints);
+
+    let output = quote! {
+        #input
+
+        #controller_impl
+    };
+    output.into()
+}
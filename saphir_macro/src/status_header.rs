@@ -0,0 +1,79 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{ItemFn, LitInt, LitStr, ReturnType};
+
+/// Expands `#[status(<code>)]`: wraps the endpoint's returned responder so
+/// its status is overridden with `<code>`, and records that code so the
+/// OpenAPI metadata can default to it instead of 200.
+pub fn expand_status(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let code = match syn::parse::<LitInt>(attr) {
+        Ok(lit) => lit,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let code_value: u16 = match code.base10_parse() {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemFn);
+
+    let ret_ty = match &input.sig.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => syn::parse_quote!(()),
+    };
+    input.sig.output = syn::parse_quote!(-> ::saphir::wrap::StatusWrap<#ret_ty>);
+
+    let original_block = input.block;
+    input.block = syn::parse_quote!({ ::saphir::wrap::StatusWrap(#original_block, #code_value) });
+
+    // If this endpoint also carries #[openapi(...)], feed `code_value` into it as
+    // a hidden `__default_status` item: `collect_openapi_items` merges every
+    // `#[openapi(...)]` attribute on a function together, so as long as
+    // `#[status(...)]` is listed above `#[openapi(...)]` (and therefore expands
+    // first), the sibling expansion picks this up and documents `code_value` as
+    // the endpoint's default return code instead of 200.
+    if input.attrs.iter().any(|a| a.path().is_ident("openapi")) {
+        input.attrs.push(syn::parse_quote!(#[openapi(__default_status = #code_value)]));
+    }
+
+    let default_status_fn = format_ident!("__openapi_default_status_{}", input.sig.ident);
+    let vis = &input.vis;
+
+    quote! {
+        #input
+
+        #[allow(non_snake_case)]
+        #vis fn #default_status_fn() -> u16 {
+            #code_value
+        }
+    }
+    .into()
+}
+
+/// Expands `#[header("<name>", "<value>")]`: wraps the endpoint's returned
+/// responder so the given header is set once it has run.
+pub fn expand_header(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let parser = |input: syn::parse::ParseStream| -> syn::Result<(LitStr, LitStr)> {
+        let name: LitStr = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let value: LitStr = input.parse()?;
+        Ok((name, value))
+    };
+    let (name, value) = match syn::parse::Parser::parse(parser, attr) {
+        Ok(v) => v,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let mut input = syn::parse_macro_input!(item as ItemFn);
+
+    let ret_ty = match &input.sig.output {
+        ReturnType::Type(_, ty) => (**ty).clone(),
+        ReturnType::Default => syn::parse_quote!(()),
+    };
+    input.sig.output = syn::parse_quote!(-> ::saphir::wrap::HeaderWrap<#ret_ty>);
+
+    let original_block = input.block;
+    let name_val = name.value();
+    let value_val = value.value();
+    input.block = syn::parse_quote!({ ::saphir::wrap::HeaderWrap(#original_block, #name_val, #value_val) });
+
+    quote! { #input }.into()
+}